@@ -0,0 +1,36 @@
+//! Key equivalence trait, shared by `HashMap` and `HashSet` lookups.
+
+use std::borrow::Borrow;
+
+/// Key equivalence trait.
+///
+/// This trait is the generalized counterpart of [`Borrow`]: it lets a lookup be performed with
+/// a query type that is only logically equal to a stored key, without requiring the query to be
+/// a borrowed view of it.
+///
+/// A blanket implementation covers every type that already satisfies the `Borrow` + [`Eq`]
+/// bound, so call sites written against that bound keep compiling unchanged.
+///
+/// # Notes
+///
+/// `flurry`'s `HashMap` stores colliding keys in ordered tree bins and needs a real [`Ord`]
+/// comparison to descend them; `Equivalent::equivalent` only produces a boolean and so cannot
+/// drive that traversal on its own. `HashSet`'s lookup methods (`contains`, `get`, `remove`,
+/// `take`) accept `Q: Equivalent<T>` directly, but fall back to a linear scan over the set's
+/// entries rather than a tree-bin descent; see [`HashSet::get`]'s notes for the cost tradeoff.
+///
+/// [`HashSet::get`]: crate::HashSet::get
+pub trait Equivalent<K: ?Sized> {
+    /// Checks if `self` is equivalent to `key`.
+    fn equivalent(&self, key: &K) -> bool;
+}
+
+impl<Q, K> Equivalent<K> for Q
+where
+    Q: ?Sized + Eq,
+    K: ?Sized + Borrow<Q>,
+{
+    fn equivalent(&self, key: &K) -> bool {
+        self == key.borrow()
+    }
+}
@@ -2,13 +2,23 @@
 //!
 //! See `HashSet` for details.
 
+use crate::equivalent::Equivalent;
 use crate::iter::Keys;
+#[cfg(feature = "rayon")]
+use crate::iter::ParKeys;
 use crate::reclaim::Guard;
 use crate::HashMap;
-use std::borrow::Borrow;
+#[cfg(feature = "serde")]
+use serde::{
+    de::{Deserialize, SeqAccess, Visitor},
+    Serialize,
+};
+use std::collections::TryReserveError;
 use std::fmt::{self, Debug, Formatter};
 use std::hash::{BuildHasher, Hash};
-use std::iter::FromIterator;
+use std::iter::{Chain, FromIterator};
+#[cfg(feature = "serde")]
+use std::marker::PhantomData;
 
 /// A concurrent hash set implemented as a `HashMap` where the value is `()`.
 ///
@@ -212,6 +222,37 @@ impl<T, S> HashSet<T, S> {
     pub fn iter<'g>(&'g self, guard: &'g Guard<'_>) -> Keys<'g, T, ()> {
         self.map.keys(guard)
     }
+
+    /// A parallel iterator visiting all elements in arbitrary order.
+    ///
+    /// The iterator element type is `&'g T`.
+    ///
+    /// Each rayon job walks a disjoint range of the underlying `HashMap`'s bins, pinning its own
+    /// guard for the duration of its chunk, so multiple threads can traverse the set at once
+    /// without serializing through a single pinned guard.
+    ///
+    /// See [`HashMap::par_keys`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "rayon")]
+    /// # {
+    /// use flurry::HashSet;
+    /// use rayon::prelude::*;
+    ///
+    /// let set = HashSet::new();
+    /// let guard = set.guard();
+    /// set.insert(1, &guard);
+    /// set.insert(2, &guard);
+    ///
+    /// set.par_iter(&guard).for_each(|x| println!("{}", x));
+    /// # }
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_iter<'g>(&'g self, guard: &'g Guard<'_>) -> ParKeys<'g, T, ()> {
+        self.map.par_keys(guard)
+    }
 }
 
 impl<T, S> HashSet<T, S>
@@ -221,12 +262,13 @@ where
 {
     /// Returns `true` if the given value is an element of this set.
     ///
-    /// The value may be any borrowed form of the set's value type, but
-    /// [`Hash`] and [`Ord`] on the borrowed form *must* match those for
-    /// the value type.
+    /// The value may be any type that is [`Equivalent`] to the set's value type, which includes
+    /// every borrowed form previously accepted through [`Borrow`] (e.g. `&str` for a
+    /// `HashSet<String>`), but also logical equivalences that are not a borrow of the stored
+    /// value at all.
     ///
-    /// [`Ord`]: std::cmp::Ord
     /// [`Hash`]: std::hash::Hash
+    /// [`Borrow`]: std::borrow::Borrow
     ///
     /// # Examples
     ///
@@ -241,22 +283,30 @@ where
     /// assert!(!set.contains(&1, &guard));
     /// ```
     #[inline]
-    pub fn contains<'g, Q>(&self, value: &Q, guard: &'g Guard<'_>) -> bool
+    pub fn contains<'g, Q>(&'g self, value: &Q, guard: &'g Guard<'_>) -> bool
     where
-        T: Borrow<Q>,
-        Q: ?Sized + Hash + Ord,
+        Q: ?Sized + Hash + Equivalent<T>,
     {
-        self.map.contains_key(value, guard)
+        self.get(value, guard).is_some()
     }
 
     /// Returns a reference to the element in the set, if any, that is equal to the given value.
     ///
-    /// The value may be any borrowed form of the set's value type, but
-    /// [`Hash`] and [`Ord`] on the borrowed form *must* match those for
-    /// the value type.
+    /// The value may be any type that is [`Equivalent`] to the set's value type, which includes
+    /// every borrowed form previously accepted through [`Borrow`] (e.g. `&str` for a
+    /// `HashSet<String>`), but also logical equivalences that are not a borrow of the stored
+    /// value at all.
     ///
-    /// [`Ord`]: std::cmp::Ord
     /// [`Hash`]: std::hash::Hash
+    /// [`Borrow`]: std::borrow::Borrow
+    ///
+    /// # Notes
+    ///
+    /// An [`Equivalent`] only tells whether two values are equal, not how they order, so it
+    /// cannot descend the underlying `HashMap`'s tree bins. This walks the set with
+    /// [`HashSet::iter`] and tests each candidate with [`Equivalent::equivalent`], i.e. it is
+    /// `O(n)` for every query type, including the borrowed forms covered by the blanket
+    /// [`Equivalent`] implementation; the `HashMap`'s own hashed lookup is not used.
     ///
     /// # Examples
     ///
@@ -270,10 +320,15 @@ where
     /// ```
     pub fn get<'g, Q>(&'g self, value: &Q, guard: &'g Guard<'_>) -> Option<&'g T>
     where
-        T: Borrow<Q>,
-        Q: ?Sized + Hash + Ord,
+        Q: ?Sized + Hash + Equivalent<T>,
     {
-        self.map.get_key_value(value, guard).map(|(k, _)| k)
+        let mut candidates = self.iter(guard);
+        loop {
+            let candidate = candidates.next()?;
+            if value.equivalent(candidate) {
+                return Some(candidate);
+            }
+        }
     }
 
     /// Returns `true` if `self` has no elements in common with `other`.
@@ -380,6 +435,169 @@ where
     ) -> bool {
         self.map.guarded_eq(&other.map, our_guard, their_guard)
     }
+
+    /// Visits the values representing the difference, i.e., the values that are in `self` but
+    /// not in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use flurry::HashSet;
+    ///
+    /// let a = HashSet::from_iter([1, 2, 3]);
+    /// let b = HashSet::from_iter([4, 2, 3, 4]);
+    ///
+    /// let guard_a = a.guard();
+    /// let guard_b = b.guard();
+    ///
+    /// // Can be seen as `a - b`.
+    /// for x in a.difference(&b, &guard_a, &guard_b) {
+    ///     println!("{}", x); // Print 1
+    /// }
+    ///
+    /// let diff: Vec<_> = a.difference(&b, &guard_a, &guard_b).collect();
+    /// assert_eq!(diff, [&1]);
+    ///
+    /// // Note that difference is not symmetric,
+    /// // and `b - a` means something else:
+    /// let diff: Vec<_> = b.difference(&a, &guard_b, &guard_a).collect();
+    /// assert_eq!(diff, [&4]);
+    /// ```
+    pub fn difference<'g>(
+        &'g self,
+        other: &'g HashSet<T, S>,
+        our_guard: &'g Guard<'_>,
+        their_guard: &'g Guard<'_>,
+    ) -> Difference<'g, T, S> {
+        Difference {
+            iter: self.iter(our_guard),
+            other,
+            guard: their_guard,
+        }
+    }
+
+    /// Visits the values representing the symmetric difference, i.e., the values that are in
+    /// `self` or in `other` but not in both.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use flurry::HashSet;
+    ///
+    /// let a = HashSet::from_iter([1, 2, 3]);
+    /// let b = HashSet::from_iter([4, 2, 3, 4]);
+    ///
+    /// let guard_a = a.guard();
+    /// let guard_b = b.guard();
+    ///
+    /// // Print 1, 4 in arbitrary order.
+    /// for x in a.symmetric_difference(&b, &guard_a, &guard_b) {
+    ///     println!("{}", x);
+    /// }
+    ///
+    /// let mut diff1: Vec<_> = a.symmetric_difference(&b, &guard_a, &guard_b).collect();
+    /// let mut diff2: Vec<_> = b.symmetric_difference(&a, &guard_b, &guard_a).collect();
+    /// diff1.sort();
+    /// diff2.sort();
+    /// assert_eq!(diff1, diff2);
+    /// assert_eq!(diff1, [&1, &4]);
+    /// ```
+    pub fn symmetric_difference<'g>(
+        &'g self,
+        other: &'g HashSet<T, S>,
+        our_guard: &'g Guard<'_>,
+        their_guard: &'g Guard<'_>,
+    ) -> SymmetricDifference<'g, T, S> {
+        SymmetricDifference {
+            iter: self
+                .difference(other, our_guard, their_guard)
+                .chain(other.difference(self, their_guard, our_guard)),
+        }
+    }
+
+    /// Visits the values representing the intersection, i.e., the values that are both in
+    /// `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use flurry::HashSet;
+    ///
+    /// let a = HashSet::from_iter([1, 2, 3]);
+    /// let b = HashSet::from_iter([4, 2, 3, 4]);
+    ///
+    /// let guard_a = a.guard();
+    /// let guard_b = b.guard();
+    ///
+    /// // Print 2, 3 in arbitrary order.
+    /// for x in a.intersection(&b, &guard_a, &guard_b) {
+    ///     println!("{}", x);
+    /// }
+    ///
+    /// let mut intersection: Vec<_> = a.intersection(&b, &guard_a, &guard_b).collect();
+    /// intersection.sort();
+    /// assert_eq!(intersection, [&2, &3]);
+    /// ```
+    pub fn intersection<'g>(
+        &'g self,
+        other: &'g HashSet<T, S>,
+        our_guard: &'g Guard<'_>,
+        their_guard: &'g Guard<'_>,
+    ) -> Intersection<'g, T, S> {
+        Intersection {
+            iter: self.iter(our_guard),
+            other,
+            guard: their_guard,
+        }
+    }
+
+    /// Visits the values representing the union, i.e., all the values in `self` or `other`,
+    /// without duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use flurry::HashSet;
+    ///
+    /// let a = HashSet::from_iter([1, 2, 3]);
+    /// let b = HashSet::from_iter([4, 2, 3, 4]);
+    ///
+    /// let guard_a = a.guard();
+    /// let guard_b = b.guard();
+    ///
+    /// // Print 1, 2, 3, 4 in arbitrary order.
+    /// for x in a.union(&b, &guard_a, &guard_b) {
+    ///     println!("{}", x);
+    /// }
+    ///
+    /// let mut union: Vec<_> = a.union(&b, &guard_a, &guard_b).collect();
+    /// union.sort();
+    /// assert_eq!(union, [&1, &2, &3, &4]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// Because the underlying set is concurrent, `union`, like the other set-algebra iterators,
+    /// is a lazy, per-bucket-consistent snapshot rather than a globally atomic one: a value
+    /// inserted into or removed from either set while the iterator is alive may be observed in
+    /// neither half, both halves, or just one, depending on exactly when it raced with the
+    /// traversal.
+    pub fn union<'g>(
+        &'g self,
+        other: &'g HashSet<T, S>,
+        our_guard: &'g Guard<'_>,
+        their_guard: &'g Guard<'_>,
+    ) -> Union<'g, T, S> {
+        Union {
+            iter: self
+                .iter(our_guard)
+                .chain(other.difference(self, their_guard, our_guard)),
+        }
+    }
 }
 
 impl<T, S> HashSet<T, S>
@@ -416,12 +634,18 @@ where
     ///
     /// If the set did have this value present, `true` is returned.
     ///
-    /// The value may be any borrowed form of the set's value type, but
-    /// [`Hash`] and [`Ord`] on the borrowed form *must* match those for
-    /// the value type.
+    /// The value may be any type that is [`Equivalent`] to the set's value type, which includes
+    /// every borrowed form previously accepted through [`Borrow`] (e.g. `&str` for a
+    /// `HashSet<String>`), but also logical equivalences that are not a borrow of the stored
+    /// value at all.
     ///
-    /// [`Ord`]: std::cmp::Ord
     /// [`Hash`]: std::hash::Hash
+    /// [`Borrow`]: std::borrow::Borrow
+    ///
+    /// # Notes
+    ///
+    /// The value is looked up with [`HashSet::get`], which walks the set and costs `O(n)` for
+    /// every query type; see its notes.
     ///
     /// # Examples
     ///
@@ -436,23 +660,30 @@ where
     /// assert!(!set.contains(&2, &guard));
     /// assert_eq!(set.remove(&2, &guard), false);
     /// ```
-    pub fn remove<Q>(&self, value: &Q, guard: &Guard<'_>) -> bool
+    pub fn remove<'g, Q>(&'g self, value: &Q, guard: &'g Guard<'_>) -> bool
     where
-        T: Borrow<Q>,
-        Q: ?Sized + Hash + Ord,
+        Q: ?Sized + Hash + Equivalent<T>,
     {
-        let removed = self.map.remove(value, guard);
-        removed.is_some()
+        match self.get(value, guard) {
+            Some(found) => self.map.remove(found, guard).is_some(),
+            None => false,
+        }
     }
 
     /// Removes and returns the value in the set, if any, that is equal to the given one.
     ///
-    /// The value may be any borrowed form of the set's value type, but
-    /// [`Hash`] and [`Ord`] on the borrowed form *must* match those for
-    /// the value type.
+    /// The value may be any type that is [`Equivalent`] to the set's value type, which includes
+    /// every borrowed form previously accepted through [`Borrow`] (e.g. `&str` for a
+    /// `HashSet<String>`), but also logical equivalences that are not a borrow of the stored
+    /// value at all.
     ///
-    /// [`Ord`]: std::cmp::Ord
     /// [`Hash`]: std::hash::Hash
+    /// [`Borrow`]: std::borrow::Borrow
+    ///
+    /// # Notes
+    ///
+    /// The value is looked up with [`HashSet::get`], which walks the set and costs `O(n)` for
+    /// every query type; see its notes.
     ///
     /// # Examples
     ///
@@ -466,10 +697,10 @@ where
     /// ```
     pub fn take<'g, Q>(&'g self, value: &Q, guard: &'g Guard<'_>) -> Option<&'g T>
     where
-        T: Borrow<Q>,
-        Q: ?Sized + Hash + Ord,
+        Q: ?Sized + Hash + Equivalent<T>,
     {
-        self.map.remove_entry(value, guard).map(|(k, _)| k)
+        let found = self.get(value, guard)?;
+        self.map.remove_entry(found, guard).map(|(k, _)| k)
     }
 
     /// Retains only the elements specified by the predicate.
@@ -495,6 +726,81 @@ where
     {
         self.map.retain(|value, ()| f(value), guard)
     }
+
+    /// Removes all elements for which `pred` returns `true` and returns an iterator over the
+    /// removed values.
+    ///
+    /// Unlike [`HashSet::retain`], which only discards the values it drops, `extract_if` hands
+    /// each removed value back to the caller, which makes it possible to move values out of the
+    /// set while filtering it, e.g. draining expired entries into a work queue.
+    ///
+    /// # Notes
+    ///
+    /// Because the set is concurrent, an element inserted by another thread while this iterator
+    /// is alive may or may not be visited, and `pred` is only ever applied to elements that were
+    /// still present at the moment they were reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flurry::HashSet;
+    ///
+    /// let set = HashSet::new();
+    /// let guard = set.guard();
+    ///
+    /// for i in 0..8 {
+    ///     set.insert(i, &guard);
+    /// }
+    ///
+    /// let evens: Vec<_> = set.extract_if(|&e| e % 2 == 0, &guard).collect();
+    /// assert_eq!(evens.len(), 4);
+    /// assert_eq!(set.len(), 4);
+    /// ```
+    pub fn extract_if<'g, F>(&'g self, pred: F, guard: &'g Guard<'_>) -> ExtractIf<'g, T, S, F>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        ExtractIf {
+            iter: self.iter(guard),
+            set: self,
+            guard,
+            pred,
+        }
+    }
+}
+
+/// An iterator that removes elements matching a predicate from a [`HashSet`], yielding the
+/// removed elements.
+///
+/// This `struct` is created by [`HashSet::extract_if`]. See its documentation for more.
+pub struct ExtractIf<'g, T, S, F> {
+    iter: Keys<'g, T, ()>,
+    set: &'g HashSet<T, S>,
+    guard: &'g Guard<'g>,
+    pred: F,
+}
+
+impl<'g, T, S, F> Iterator for ExtractIf<'g, T, S, F>
+where
+    T: Sync + Send + Clone + Hash + Ord,
+    S: BuildHasher,
+    F: FnMut(&T) -> bool,
+{
+    type Item = &'g T;
+
+    fn next(&mut self) -> Option<&'g T> {
+        loop {
+            let value = self.iter.next()?;
+            if (self.pred)(value) {
+                // Another thread (or another `extract_if`/`remove` call) may have already taken
+                // this exact value between `self.iter` yielding it and us taking it here; if so,
+                // keep pulling from `self.iter` instead of ending the iteration early.
+                if let Some(removed) = self.set.take(value, self.guard) {
+                    return Some(removed);
+                }
+            }
+        }
+    }
 }
 
 impl<T, S> HashSet<T, S>
@@ -525,6 +831,224 @@ where
     pub fn reserve(&self, additional: usize, guard: &Guard<'_>) {
         self.map.reserve(additional, guard)
     }
+
+    /// Tries to reserve capacity for at least `additional` more elements to be inserted in the
+    /// `HashSet`, returning an error instead of panicking if the necessary allocation fails.
+    ///
+    /// The collection may reserve more space to avoid frequent reallocations.
+    ///
+    /// # Errors
+    ///
+    /// If the table grows and the allocator reports an allocation failure, a [`TryReserveError`]
+    /// is returned, leaving the set's existing elements untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flurry::HashSet;
+    ///
+    /// let set = HashSet::new();
+    /// let guard = set.guard();
+    /// set.try_reserve(10, &guard).expect("why is the test harness OOMing on a capacity of 10?");
+    /// ```
+    pub fn try_reserve(&self, additional: usize, guard: &Guard<'_>) -> Result<(), TryReserveError> {
+        self.map.try_reserve(additional, guard)
+    }
+}
+
+/// A lazy iterator producing elements in the difference of `HashSet`s.
+///
+/// This `struct` is created by [`HashSet::difference`]. See its documentation for more.
+///
+/// Because the underlying set is concurrent, the elements yielded are a per-bucket-consistent
+/// but not globally atomic snapshot: a concurrent insert or remove racing with this iterator may
+/// cause an element to be observed in neither set, or in both.
+pub struct Difference<'g, T, S> {
+    // iterator of the first set
+    iter: Keys<'g, T, ()>,
+    other: &'g HashSet<T, S>,
+    guard: &'g Guard<'g>,
+}
+
+impl<'g, T, S> Iterator for Difference<'g, T, S>
+where
+    T: Hash + Ord,
+    S: BuildHasher,
+{
+    type Item = &'g T;
+
+    fn next(&mut self) -> Option<&'g T> {
+        loop {
+            let elt = self.iter.next()?;
+            if !self.other.contains(elt, self.guard) {
+                return Some(elt);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<T, S> Debug for Difference<'_, T, S>
+where
+    T: Debug + Hash + Ord,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<T, S> Clone for Difference<'_, T, S> {
+    fn clone(&self) -> Self {
+        Difference {
+            iter: self.iter.clone(),
+            other: self.other,
+            guard: self.guard,
+        }
+    }
+}
+
+/// A lazy iterator producing elements in the intersection of `HashSet`s.
+///
+/// This `struct` is created by [`HashSet::intersection`]. See its documentation for more.
+///
+/// See [`Difference`] for a note on the snapshot consistency of the elements yielded.
+pub struct Intersection<'g, T, S> {
+    // iterator of the first set
+    iter: Keys<'g, T, ()>,
+    other: &'g HashSet<T, S>,
+    guard: &'g Guard<'g>,
+}
+
+impl<'g, T, S> Iterator for Intersection<'g, T, S>
+where
+    T: Hash + Ord,
+    S: BuildHasher,
+{
+    type Item = &'g T;
+
+    fn next(&mut self) -> Option<&'g T> {
+        loop {
+            let elt = self.iter.next()?;
+            if self.other.contains(elt, self.guard) {
+                return Some(elt);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<T, S> Debug for Intersection<'_, T, S>
+where
+    T: Debug + Hash + Ord,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<T, S> Clone for Intersection<'_, T, S> {
+    fn clone(&self) -> Self {
+        Intersection {
+            iter: self.iter.clone(),
+            other: self.other,
+            guard: self.guard,
+        }
+    }
+}
+
+/// A lazy iterator producing elements in the symmetric difference of `HashSet`s.
+///
+/// This `struct` is created by [`HashSet::symmetric_difference`]. See its documentation for
+/// more.
+///
+/// See [`Difference`] for a note on the snapshot consistency of the elements yielded.
+pub struct SymmetricDifference<'g, T, S> {
+    iter: Chain<Difference<'g, T, S>, Difference<'g, T, S>>,
+}
+
+impl<'g, T, S> Iterator for SymmetricDifference<'g, T, S>
+where
+    T: Hash + Ord,
+    S: BuildHasher,
+{
+    type Item = &'g T;
+
+    fn next(&mut self) -> Option<&'g T> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, S> Debug for SymmetricDifference<'_, T, S>
+where
+    T: Debug + Hash + Ord,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<T, S> Clone for SymmetricDifference<'_, T, S> {
+    fn clone(&self) -> Self {
+        SymmetricDifference {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+/// A lazy iterator producing elements in the union of `HashSet`s.
+///
+/// This `struct` is created by [`HashSet::union`]. See its documentation for more.
+///
+/// See [`Difference`] for a note on the snapshot consistency of the elements yielded.
+pub struct Union<'g, T, S> {
+    iter: Chain<Keys<'g, T, ()>, Difference<'g, T, S>>,
+}
+
+impl<'g, T, S> Iterator for Union<'g, T, S>
+where
+    T: Hash + Ord,
+    S: BuildHasher,
+{
+    type Item = &'g T;
+
+    fn next(&mut self) -> Option<&'g T> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, S> Debug for Union<'_, T, S>
+where
+    T: Debug + Hash + Ord,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<T, S> Clone for Union<'_, T, S> {
+    fn clone(&self) -> Self {
+        Union {
+            iter: self.iter.clone(),
+        }
+    }
 }
 
 impl<T, S> PartialEq for HashSet<T, S>
@@ -598,6 +1122,71 @@ where
     }
 }
 
+/// Parallel extend for [`HashSet`], requires the `rayon` feature flag to be enabled.
+///
+/// Each worker in the `rayon` thread pool pins its own `Guard` and inserts its share of the
+/// items directly into the underlying `HashMap`, so the fan-out is not serialized behind a
+/// single pinned guard.
+///
+/// # Notes
+///
+/// Since `HashSet` is a concurrent data structure, the `extend` call can be performed by
+/// multiple threads at the same time. Since the `&HashSet` is shared among the threads,
+/// internal guards are used for synchronization. See the crate-level documentation for details.
+#[cfg(feature = "rayon")]
+impl<T, S> rayon::iter::ParallelExtend<T> for &HashSet<T, S>
+where
+    T: Send + Sync + Clone + Hash + Ord,
+    S: BuildHasher + Sync,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: rayon::iter::IntoParallelIterator<Item = T>,
+    {
+        rayon::iter::ParallelExtend::par_extend(
+            &mut &self.map,
+            par_iter.into_par_iter().map(|v| (v, ())),
+        );
+    }
+}
+
+/// Parallel extend for [`HashSet`] with references, requires the `rayon` feature flag to be
+/// enabled.
+#[cfg(feature = "rayon")]
+impl<'a, T, S> rayon::iter::ParallelExtend<&'a T> for &HashSet<T, S>
+where
+    T: Send + Sync + Copy + Hash + Ord,
+    S: BuildHasher + Sync,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: rayon::iter::IntoParallelIterator<Item = &'a T>,
+    {
+        rayon::iter::ParallelExtend::par_extend(
+            &mut &self.map,
+            par_iter.into_par_iter().map(|&v| (v, ())),
+        );
+    }
+}
+
+/// Parallel construction of a [`HashSet`] from a parallel iterator, requires the `rayon`
+/// feature flag to be enabled.
+#[cfg(feature = "rayon")]
+impl<T, S> rayon::iter::FromParallelIterator<T> for HashSet<T, S>
+where
+    T: Send + Sync + Clone + Hash + Ord,
+    S: BuildHasher + Default + Sync,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: rayon::iter::IntoParallelIterator<Item = T>,
+    {
+        Self {
+            map: par_iter.into_par_iter().map(|v| (v, ())).collect(),
+        }
+    }
+}
+
 impl<T, S> Clone for HashSet<T, S>
 where
     T: Sync + Send + Clone + Hash + Ord,
@@ -609,3 +1198,67 @@ where
         }
     }
 }
+
+#[cfg(feature = "serde")]
+impl<T, S> serde::Serialize for HashSet<T, S>
+where
+    T: Serialize + Hash + Ord,
+    S: BuildHasher,
+{
+    fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
+    where
+        Se: serde::Serializer,
+    {
+        let guard = self.guard();
+        serializer.collect_seq(self.iter(&guard))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, S> Deserialize<'de> for HashSet<T, S>
+where
+    T: Sync + Send + Clone + Hash + Ord + Deserialize<'de>,
+    S: BuildHasher + Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct HashSetVisitor<T, S> {
+            marker: PhantomData<HashSet<T, S>>,
+        }
+
+        impl<'de, T, S> Visitor<'de> for HashSetVisitor<T, S>
+        where
+            T: Sync + Send + Clone + Hash + Ord + Deserialize<'de>,
+            S: BuildHasher + Default,
+        {
+            type Value = HashSet<T, S>;
+
+            fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a set")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let set =
+                    HashSet::with_capacity_and_hasher(seq.size_hint().unwrap_or(0), S::default());
+                {
+                    let guard = set.guard();
+                    while let Some(value) = seq.next_element()? {
+                        set.insert(value, &guard);
+                    }
+                }
+
+                Ok(set)
+            }
+        }
+
+        let visitor = HashSetVisitor {
+            marker: PhantomData,
+        };
+        deserializer.deserialize_seq(visitor)
+    }
+}